@@ -52,7 +52,11 @@ extern crate alloc;
 
 #[cfg(feature = "serde")]
 use alloc::borrow::ToOwned;
+#[cfg(any(feature = "fancy", feature = "stream"))]
+use alloc::boxed::Box;
 use alloc::string::String;
+#[cfg(feature = "stream")]
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use core::fmt;
 use core::{
@@ -60,12 +64,16 @@ use core::{
     ops::Deref,
 };
 use regex::Regex;
-#[cfg(feature = "serde")]
-use regex::RegexBuilder;
+#[cfg(feature = "stream")]
+use regex_automata::{
+    dfa::{dense, Automaton},
+    util::{primitives::StateID, start},
+    Anchored,
+};
 #[cfg(feature = "serde")]
 use serde::{
-    de::{Error, Unexpected, Visitor},
-    Deserialize, Deserializer,
+    de::{DeserializeSeed, Error, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
 /// `Matchable` is a wrapper for a plain string or a regex, and it's used to check matching.
@@ -78,10 +86,17 @@ use serde::{
 /// if the value starts with a slash `/`, and it ends with a slash `/` with optional regex flags,
 /// like `"/abcd/"` or `"/abcd/i"`, it will be deserialized as a regex;
 /// otherwise, it will be deserialized as a plain string.
+///
+/// When the `fancy` feature is enabled, a pattern that the default [`regex`]
+/// engine rejects (because it uses lookaround or backreferences) falls back to
+/// the [`fancy_regex`] engine and is stored in the [`Fancy`](Matchable::Fancy)
+/// variant; ordinary patterns keep using the default engine unchanged.
 #[derive(Clone, Debug)]
 pub enum Matchable {
     Str(String),
     Regex(Regex),
+    #[cfg(feature = "fancy")]
+    Fancy(fancy_regex::Regex),
 }
 
 impl Matchable {
@@ -105,6 +120,79 @@ impl Matchable {
         match self {
             Self::Str(str) => str == text,
             Self::Regex(regex) => regex.is_match(text),
+            // An evaluation error from the fancy engine is treated as a
+            // non-match; use [`try_is_match`](Matchable::try_is_match) to
+            // observe it.
+            #[cfg(feature = "fancy")]
+            Self::Fancy(regex) => regex.is_match(text).unwrap_or(false),
+        }
+    }
+
+    /// Like [`is_match`](Matchable::is_match), but surfaces evaluation errors
+    /// from the [`fancy_regex`] engine instead of treating them as a non-match.
+    ///
+    /// The [`Str`](Matchable::Str) and [`Regex`](Matchable::Regex) variants can
+    /// never error, so this only ever returns `Err` for the
+    /// [`Fancy`](Matchable::Fancy) variant.
+    #[cfg(feature = "fancy")]
+    #[inline]
+    pub fn try_is_match(&self, text: impl AsRef<str>) -> Result<bool, Box<fancy_regex::Error>> {
+        let text = text.as_ref();
+        match self {
+            Self::Str(str) => Ok(str == text),
+            Self::Regex(regex) => Ok(regex.is_match(text)),
+            Self::Fancy(regex) => regex.is_match(text).map_err(Box::new),
+        }
+    }
+
+    /// Find the byte span of the match within `text`, if any.
+    ///
+    /// For the [`Str`](Matchable::Str) variant this is the span of the whole
+    /// string when it equals `text`; for the [`Regex`](Matchable::Regex)
+    /// variant it's the leftmost match.
+    ///
+    /// ```
+    /// use matchable::Matchable;
+    ///
+    /// assert_eq!(Matchable::Str("abc".into()).find("abc"), Some((0, 3)));
+    /// assert_eq!(Matchable::Str("abc".into()).find("xabc"), None);
+    ///
+    /// let re = Matchable::Regex(regex::Regex::new("b.").unwrap());
+    /// assert_eq!(re.find("abcd"), Some((1, 3)));
+    /// ```
+    #[inline]
+    pub fn find(&self, text: impl AsRef<str>) -> Option<(usize, usize)> {
+        let text = text.as_ref();
+        match self {
+            Self::Str(str) => (str == text).then_some((0, text.len())),
+            Self::Regex(regex) => regex.find(text).map(|m| (m.start(), m.end())),
+            #[cfg(feature = "fancy")]
+            Self::Fancy(regex) => regex.find(text).ok().flatten().map(|m| (m.start(), m.end())),
+        }
+    }
+
+    /// Extract the capture groups of the match within `text`, if any.
+    ///
+    /// The [`Str`](Matchable::Str) variant exposes a single implicit group `0`
+    /// spanning the whole string when it equals `text`; the
+    /// [`Regex`](Matchable::Regex) variant exposes its numbered and named
+    /// groups.
+    ///
+    /// ```
+    /// use matchable::Matchable;
+    ///
+    /// let re = Matchable::Regex(regex::Regex::new("(?<year>\\d{4})").unwrap());
+    /// let caps = re.captures("in 2024").unwrap();
+    /// assert_eq!(caps.get(0).unwrap().as_str(), "2024");
+    /// assert_eq!(caps.name("year").unwrap().as_str(), "2024");
+    /// ```
+    #[inline]
+    pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        match self {
+            Self::Str(str) => (str == text).then_some(Captures::Str(text)),
+            Self::Regex(regex) => regex.captures(text).map(Captures::Regex),
+            #[cfg(feature = "fancy")]
+            Self::Fancy(regex) => regex.captures(text).ok().flatten().map(Captures::Fancy),
         }
     }
 
@@ -114,6 +202,267 @@ impl Matchable {
         match self {
             Self::Str(str) => str,
             Self::Regex(regex) => regex.as_str(),
+            #[cfg(feature = "fancy")]
+            Self::Fancy(regex) => regex.as_str(),
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+impl Matchable {
+    /// Build an incremental [`Matcher`] that tests this pattern against input
+    /// arriving in pieces, without concatenating it into one string first.
+    ///
+    /// The [`Regex`](Matchable::Regex) variant is compiled to a byte automaton;
+    /// the [`Str`](Matchable::Str) variant degrades to a rolling prefix
+    /// comparator.
+    ///
+    /// ```
+    /// use matchable::Matchable;
+    ///
+    /// let matchable = Matchable::Regex(regex::Regex::new("\\d+").unwrap());
+    /// let mut matcher = matchable.matcher().unwrap();
+    /// matcher.advance(b"ab");
+    /// matcher.advance(b"12");
+    /// assert!(matcher.matches());
+    /// ```
+    pub fn matcher(&self) -> Result<Matcher, MatcherError> {
+        match self {
+            Self::Str(str) => Ok(Matcher {
+                state: MatcherState::Str {
+                    expected: str.as_bytes().to_vec(),
+                    pos: 0,
+                    failed: false,
+                },
+            }),
+            Self::Regex(regex) => {
+                let dfa = dense::DFA::new(regex.as_str()).map_err(Box::new)?;
+                let current = dfa
+                    .start_state(&start::Config::new().anchored(Anchored::No))
+                    .map_err(|_| MatcherError::Unsupported)?;
+                let matched = dfa.is_match_state(current);
+                Ok(Matcher {
+                    state: MatcherState::Regex {
+                        dfa: Box::new(dfa),
+                        current,
+                        matched,
+                    },
+                })
+            }
+            #[cfg(feature = "fancy")]
+            Self::Fancy(_) => Err(MatcherError::Unsupported),
+        }
+    }
+}
+
+/// The error returned by [`Matchable::matcher`] when an incremental matcher
+/// cannot be built for a pattern.
+#[cfg(feature = "stream")]
+#[derive(Debug)]
+pub enum MatcherError {
+    /// The pattern could not be compiled to a byte automaton.
+    Build(Box<dense::BuildError>),
+    /// The pattern has no byte-automaton representation (e.g. the
+    /// [`Fancy`](Matchable::Fancy) variant, which relies on lookaround or
+    /// backreferences).
+    Unsupported,
+}
+
+#[cfg(feature = "stream")]
+impl From<Box<dense::BuildError>> for MatcherError {
+    #[inline]
+    fn from(err: Box<dense::BuildError>) -> Self {
+        MatcherError::Build(err)
+    }
+}
+
+/// An incremental matcher, produced by [`Matchable::matcher`].
+///
+/// Feed bytes with [`advance`](Matcher::advance) and query
+/// [`matches`](Matcher::matches). Feeding the full input yields the same
+/// result as [`Matchable::is_match`] on the joined bytes, no matter where the
+/// chunk boundaries fall.
+///
+/// The regex automaton is compiled from the pattern's `as_str()`, so — like
+/// [serialization](Matchable) — it sees only inline flags. A
+/// [`Regex`](Matchable::Regex) built with out-of-band
+/// [`RegexBuilder`](regex::RegexBuilder) options matches case-sensitively here
+/// regardless of those options; use inline flags (`(?i)…`) to carry them.
+#[cfg(feature = "stream")]
+pub struct Matcher {
+    state: MatcherState,
+}
+
+#[cfg(feature = "stream")]
+enum MatcherState {
+    Str {
+        expected: Vec<u8>,
+        pos: usize,
+        failed: bool,
+    },
+    Regex {
+        dfa: Box<dense::DFA<Vec<u32>>>,
+        current: StateID,
+        matched: bool,
+    },
+}
+
+#[cfg(feature = "stream")]
+impl Matcher {
+    /// Feed the next chunk of input, updating the current state.
+    pub fn advance(&mut self, chunk: &[u8]) {
+        match &mut self.state {
+            MatcherState::Str {
+                expected,
+                pos,
+                failed,
+            } => {
+                if *failed {
+                    return;
+                }
+                for &byte in chunk {
+                    if *pos >= expected.len() || expected[*pos] != byte {
+                        *failed = true;
+                        return;
+                    }
+                    *pos += 1;
+                }
+            }
+            MatcherState::Regex {
+                dfa,
+                current,
+                matched,
+            } => {
+                if *matched {
+                    return;
+                }
+                for &byte in chunk {
+                    *current = dfa.next_state(*current, byte);
+                    if dfa.is_match_state(*current) {
+                        *matched = true;
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether the input fed so far has reached an accepting state.
+    ///
+    /// For the string comparator this means exactly the expected bytes have
+    /// been seen; for the regex automaton it means a match has been found,
+    /// including one that ends at the current end of input.
+    pub fn matches(&self) -> bool {
+        match &self.state {
+            MatcherState::Str {
+                expected,
+                pos,
+                failed,
+            } => !*failed && *pos == expected.len(),
+            MatcherState::Regex {
+                dfa,
+                current,
+                matched,
+            } => *matched || dfa.is_match_state(dfa.next_eoi_state(*current)),
+        }
+    }
+}
+
+/// A single matched span, carrying its byte offsets and the matched text.
+///
+/// Returned by the accessors on [`Captures`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+}
+
+impl<'t> Match<'t> {
+    /// The byte offset of the start of the match.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The byte offset of the end of the match.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// The matched text.
+    #[inline]
+    pub fn as_str(&self) -> &'t str {
+        self.text
+    }
+}
+
+impl<'t> From<regex::Match<'t>> for Match<'t> {
+    #[inline]
+    fn from(m: regex::Match<'t>) -> Self {
+        Match {
+            text: m.as_str(),
+            start: m.start(),
+            end: m.end(),
+        }
+    }
+}
+
+#[cfg(feature = "fancy")]
+impl<'t> From<fancy_regex::Match<'t>> for Match<'t> {
+    #[inline]
+    fn from(m: fancy_regex::Match<'t>) -> Self {
+        Match {
+            text: m.as_str(),
+            start: m.start(),
+            end: m.end(),
+        }
+    }
+}
+
+/// The capture groups of a match, produced by [`Matchable::captures`].
+///
+/// The [`Str`](Captures::Str) variant holds the matched string and exposes
+/// only the implicit group `0`; the [`Regex`](Captures::Regex) variant defers
+/// to the underlying [`regex::Captures`].
+#[derive(Debug)]
+pub enum Captures<'t> {
+    Str(&'t str),
+    Regex(regex::Captures<'t>),
+    #[cfg(feature = "fancy")]
+    Fancy(fancy_regex::Captures<'t>),
+}
+
+impl<'t> Captures<'t> {
+    /// Return the match for the capture group at index `i`, if it participated.
+    ///
+    /// Group `0` always refers to the whole match.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<Match<'t>> {
+        match self {
+            Self::Str(text) => (i == 0).then_some(Match {
+                text,
+                start: 0,
+                end: text.len(),
+            }),
+            Self::Regex(caps) => caps.get(i).map(Match::from),
+            #[cfg(feature = "fancy")]
+            Self::Fancy(caps) => caps.get(i).map(Match::from),
+        }
+    }
+
+    /// Return the match for the named capture group, if it participated.
+    ///
+    /// The [`Str`](Captures::Str) variant has no named groups and always
+    /// returns `None`.
+    #[inline]
+    pub fn name(&self, name: &str) -> Option<Match<'t>> {
+        match self {
+            Self::Str(_) => None,
+            Self::Regex(caps) => caps.name(name).map(Match::from),
+            #[cfg(feature = "fancy")]
+            Self::Fancy(caps) => caps.name(name).map(Match::from),
         }
     }
 }
@@ -129,6 +478,8 @@ impl Hash for Matchable {
         match self {
             Self::Str(str) => str.hash(state),
             Self::Regex(regex) => regex.as_str().hash(state),
+            #[cfg(feature = "fancy")]
+            Self::Fancy(regex) => regex.as_str().hash(state),
         };
     }
 }
@@ -138,6 +489,8 @@ impl PartialEq for Matchable {
         match (self, other) {
             (Matchable::Str(a), Matchable::Str(b)) => a == b,
             (Matchable::Regex(a), Matchable::Regex(b)) => a.as_str() == b.as_str(),
+            #[cfg(feature = "fancy")]
+            (Matchable::Fancy(a), Matchable::Fancy(b)) => a.as_str() == b.as_str(),
             _ => false,
         }
     }
@@ -155,6 +508,186 @@ impl<'de> Deserialize<'de> for Matchable {
     }
 }
 
+/// Configures how deserialized patterns compile into a [`Matchable`], adding
+/// grep-style matching modes on top of the default behavior.
+///
+/// A builder can be used directly with [`build`](MatchableBuilder::build), or
+/// as a Serde [`DeserializeSeed`] to deserialize through the same
+/// slash-delimited path while applying the configured modes:
+///
+/// ```
+/// use matchable::MatchableBuilder;
+/// use serde::de::DeserializeSeed;
+///
+/// let seed = MatchableBuilder::new().whole_word(true).clone();
+/// let mut de = serde_json::Deserializer::from_str(r#""/cat/""#);
+/// let matchable = seed.deserialize(&mut de).unwrap();
+/// assert!(matchable.is_match("the cat sat"));
+/// assert!(!matchable.is_match("category"));
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default)]
+pub struct MatchableBuilder {
+    smart_case: bool,
+    whole_word: bool,
+    anchored: bool,
+}
+
+#[cfg(feature = "serde")]
+impl MatchableBuilder {
+    /// Create a builder with every mode disabled, matching the default
+    /// [`Deserialize`] behavior (including the `fancy` fallback when that
+    /// feature is enabled).
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compile case-insensitively when the pattern contains no uppercase
+    /// character; as soon as it contains one it stays case-sensitive. Every
+    /// character of the pattern counts, including those in escapes such as
+    /// `\W` or `\p{Lu}` and in group names, so such a pattern stays
+    /// case-sensitive.
+    ///
+    /// This also promotes a plain-string pattern without uppercase to a
+    /// case-insensitive regex of the escaped literal.
+    #[inline]
+    pub fn smart_case(&mut self, yes: bool) -> &mut Self {
+        self.smart_case = yes;
+        self
+    }
+
+    /// Wrap the compiled regex in `\b(?:…)\b` so matches land on word
+    /// boundaries.
+    #[inline]
+    pub fn whole_word(&mut self, yes: bool) -> &mut Self {
+        self.whole_word = yes;
+        self
+    }
+
+    /// Wrap the compiled regex in `\A(?:…)\z` so `is_match` requires a
+    /// full-string match even for the regex variant, also when the `m` flag
+    /// is set.
+    #[inline]
+    pub fn anchored(&mut self, yes: bool) -> &mut Self {
+        self.anchored = yes;
+        self
+    }
+
+    /// Compile `input` into a [`Matchable`], applying the configured modes.
+    ///
+    /// A slash-delimited value is treated as a regex just like the default
+    /// deserializer; any other value is a plain string, which the
+    /// [`smart_case`](MatchableBuilder::smart_case) mode may promote to a
+    /// case-insensitive regex.
+    pub fn build(&self, input: &str) -> Result<Matchable, regex::Error> {
+        if let Some((body, flags)) = extract_regex(input) {
+            self.compile(body, flags)
+        } else if self.smart_case && !has_uppercase(input) {
+            self.compile(&regex::escape(input), "")
+        } else {
+            Ok(Matchable::Str(input.to_owned()))
+        }
+    }
+
+    /// Wrap `body` in the configured boundary assertions and compile it with
+    /// the explicit flags plus any implied by smart-case, falling back to the
+    /// `fancy` engine like the default deserializer.
+    fn compile(&self, body: &str, flags: &str) -> Result<Matchable, regex::Error> {
+        let mut pattern = String::from(body);
+        if self.whole_word {
+            pattern = alloc::format!(r"\b(?:{pattern})\b");
+        }
+        if self.anchored {
+            pattern = alloc::format!(r"\A(?:{pattern})\z");
+        }
+
+        let mut flags = String::from(flags);
+        if self.smart_case && !has_uppercase(body) && !flags.contains('i') {
+            flags.push('i');
+        }
+        match build_regex(&pattern, &flags) {
+            Ok(re) => Ok(Matchable::Regex(re)),
+            #[cfg(feature = "fancy")]
+            Err(err) => build_fancy(&pattern, &flags)
+                .map(Matchable::Fancy)
+                .map_err(|_| err),
+            #[cfg(not(feature = "fancy"))]
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Whether `s` contains an uppercase letter, used by smart-case detection.
+#[cfg(feature = "serde")]
+fn has_uppercase(s: &str) -> bool {
+    s.chars().any(|c| c.is_uppercase())
+}
+
+#[cfg(feature = "serde")]
+impl<'de> DeserializeSeed<'de> for MatchableBuilder {
+    type Value = Matchable;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Matchable, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(MatchableBuilderVisitor(self))
+    }
+}
+
+/// Serde visitor that applies a [`MatchableBuilder`]'s modes while parsing.
+#[cfg(feature = "serde")]
+struct MatchableBuilderVisitor(MatchableBuilder);
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for MatchableBuilderVisitor {
+    type Value = Matchable;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a normal string or a string that represents a regex"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0
+            .build(v)
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &"a valid regex"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Matchable {
+    /// Serialize back to the string form [`Deserialize`] accepts: the
+    /// [`Str`](Matchable::Str) variant as its raw string, and the
+    /// [`Regex`](Matchable::Regex) variant as `/pattern/flags`.
+    ///
+    /// The flag suffix is reconstructed from a leading inline flag group, which
+    /// is how this crate's deserializer stores flags. A regex built directly
+    /// with a [`RegexBuilder`](regex::RegexBuilder) (e.g.
+    /// `RegexBuilder::new("abc").case_insensitive(true).build()`) carries its
+    /// options out of band where the `regex` crate exposes no getter for them,
+    /// so those flags cannot be recovered and the value serializes as
+    /// `/abc/`. Round-tripping is therefore stable only for values produced by
+    /// this crate's deserializer or with inline flags in the pattern itself.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Str(str) => serializer.serialize_str(str),
+            Self::Regex(regex) => serializer.serialize_str(&regex_to_literal(regex.as_str())),
+            #[cfg(feature = "fancy")]
+            Self::Fancy(regex) => serializer.serialize_str(&regex_to_literal(regex.as_str())),
+        }
+    }
+}
+
 /// Serde visitor for parsing string as the [`Matchable`] type.
 #[cfg(feature = "serde")]
 struct MatchableVisitor;
@@ -175,9 +708,7 @@ impl<'de> Visitor<'de> for MatchableVisitor {
         E: Error,
     {
         if let Some((regex, flags)) = extract_regex(v) {
-            build_regex(regex, flags)
-                .map(Matchable::Regex)
-                .map_err(|_| E::invalid_value(Unexpected::Str(regex), &"a valid regex"))
+            compile_matchable(regex, flags)
         } else {
             Ok(Matchable::Str(v.to_owned()))
         }
@@ -188,9 +719,7 @@ impl<'de> Visitor<'de> for MatchableVisitor {
         E: Error,
     {
         if let Some((regex, flags)) = extract_regex(&v) {
-            build_regex(regex, flags)
-                .map(Matchable::Regex)
-                .map_err(|_| E::invalid_value(Unexpected::Str(regex), &"a valid regex"))
+            compile_matchable(regex, flags)
         } else {
             Ok(Matchable::Str(v))
         }
@@ -221,6 +750,16 @@ impl<'de> Deserialize<'de> for RegexOnly {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for RegexOnly {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
 /// Serde visitor for parsing string as the [`RegexOnly`](RegexOnly) type.
 #[cfg(feature = "serde")]
 struct RegexOnlyVisitor;
@@ -250,11 +789,225 @@ fn extract_regex(s: &str) -> Option<(&str, &str)> {
 
 #[cfg(feature = "serde")]
 fn build_regex(regex: &str, flags: &str) -> Result<Regex, regex::Error> {
-    let mut builder = RegexBuilder::new(regex);
-    builder.case_insensitive(flags.contains('i'));
-    builder.multi_line(flags.contains('m'));
-    builder.dot_matches_new_line(flags.contains('s'));
-    builder.build()
+    // Embed the flags as a leading inline group rather than configuring a
+    // `RegexBuilder`, so they remain recoverable from the compiled pattern's
+    // `as_str()` when serializing back to the slash-delimited form.
+    Regex::new(&prefix_inline_flags(regex, flags))
+}
+
+/// Prepend the slash-delimited `flags` as a leading inline group (`(?ims)`) to
+/// `regex`, or return it unchanged when there are no flags.
+#[cfg(feature = "serde")]
+fn prefix_inline_flags(regex: &str, flags: &str) -> String {
+    let inline = inline_flags(flags);
+    if inline.is_empty() {
+        return String::from(regex);
+    }
+    let mut pattern = String::with_capacity(regex.len() + inline.len() + 3);
+    pattern.push_str("(?");
+    pattern.push_str(&inline);
+    pattern.push(')');
+    pattern.push_str(regex);
+    pattern
+}
+
+/// Compile the regex body and flags into a [`Matchable::Regex`].
+///
+/// When the `fancy` feature is enabled and the default engine rejects the
+/// pattern (typically because it uses lookaround or backreferences), the
+/// [`fancy_regex`] engine is tried as a fallback, yielding a
+/// [`Matchable::Fancy`]. Patterns the default engine accepts never reach the
+/// fallback, so their behavior is unchanged.
+#[cfg(feature = "serde")]
+fn compile_matchable<E>(regex: &str, flags: &str) -> Result<Matchable, E>
+where
+    E: Error,
+{
+    match build_regex(regex, flags) {
+        Ok(re) => Ok(Matchable::Regex(re)),
+        Err(_err) => {
+            #[cfg(feature = "fancy")]
+            {
+                build_fancy(regex, flags)
+                    .map(Matchable::Fancy)
+                    .map_err(|_| E::invalid_value(Unexpected::Str(regex), &"a valid regex"))
+            }
+            #[cfg(not(feature = "fancy"))]
+            {
+                Err(E::invalid_value(Unexpected::Str(regex), &"a valid regex"))
+            }
+        }
+    }
+}
+
+/// Build an inline flag prefix (`(?ims)`) from the slash-delimited flag suffix.
+#[cfg(feature = "serde")]
+fn inline_flags(flags: &str) -> String {
+    let mut inline = String::new();
+    if flags.contains('i') {
+        inline.push('i');
+    }
+    if flags.contains('m') {
+        inline.push('m');
+    }
+    if flags.contains('s') {
+        inline.push('s');
+    }
+    inline
+}
+
+/// Compile a pattern with the [`fancy_regex`] engine, embedding the flags as a
+/// leading inline group just like [`build_regex`] does.
+#[cfg(all(feature = "serde", feature = "fancy"))]
+fn build_fancy(regex: &str, flags: &str) -> Result<fancy_regex::Regex, Box<fancy_regex::Error>> {
+    fancy_regex::Regex::new(&prefix_inline_flags(regex, flags)).map_err(Box::new)
+}
+
+/// Render a compiled regex pattern back to the `/pattern/flags` form the
+/// deserializer accepts, turning a leading inline flag group (as produced by
+/// [`build_regex`]) back into the trailing flag suffix.
+///
+/// Only inline flags are recoverable; options set out of band via a
+/// [`RegexBuilder`](regex::RegexBuilder) are invisible here, as the `regex`
+/// crate exposes no getter for them. See [`Serialize for Matchable`](Matchable).
+#[cfg(feature = "serde")]
+fn regex_to_literal(pattern: &str) -> String {
+    if let Some(rest) = pattern.strip_prefix("(?") {
+        if let Some((flags, body)) = rest.split_once(')') {
+            if !flags.is_empty() && flags.bytes().all(|b| matches!(b, b'i' | b'm' | b's')) {
+                let mut literal = String::with_capacity(body.len() + flags.len() + 2);
+                literal.push('/');
+                literal.push_str(body);
+                literal.push('/');
+                literal.push_str(flags);
+                return literal;
+            }
+        }
+    }
+    let mut literal = String::with_capacity(pattern.len() + 2);
+    literal.push('/');
+    literal.push_str(pattern);
+    literal.push('/');
+    literal
+}
+
+/// A data-driven test-suite loader for validating user-authored config
+/// patterns against [`Matchable`] and [`RegexOnly`].
+///
+/// Cases are `{ pattern, input, expected_match, flags }` tables, in the
+/// Fowler/automata style of external regex test collections, loaded from JSON
+/// (or any other Serde format) and run through the same slash-delimited path as
+/// production. Downstream crates embedding `matchable` as their config pattern
+/// type can ship these as regression fixtures for user patterns.
+#[cfg(feature = "testing")]
+pub mod testing {
+    use super::{Matchable, RegexOnly};
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use regex::Regex;
+    use serde::Deserialize;
+
+    /// A single test case, deserialized from a `{ pattern, input,
+    /// expected_match, flags }` table.
+    #[derive(Clone, Debug, Deserialize)]
+    pub struct Case {
+        /// The regex body, without the enclosing slashes.
+        pub pattern: String,
+        /// The text to test against.
+        pub input: String,
+        /// Whether the pattern is expected to match the input.
+        pub expected_match: bool,
+        /// The flag suffix (`i`, `m`, `s`), empty if none.
+        #[serde(default)]
+        pub flags: String,
+    }
+
+    impl Case {
+        /// The slash-delimited literal this case deserializes through, e.g.
+        /// `/\d+/i`.
+        pub fn literal(&self) -> String {
+            format!("/{}/{}", self.pattern, self.flags)
+        }
+    }
+
+    /// The result of running a single [`Case`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Outcome {
+        /// The match result agreed with `expected_match`.
+        Pass,
+        /// The pattern compiled and ran, but disagreed with `expected_match`.
+        Mismatch { expected: bool, actual: bool },
+        /// The pattern failed to compile.
+        CompileError,
+    }
+
+    /// A [`Case`] paired with its [`Outcome`].
+    #[derive(Clone, Debug)]
+    pub struct CaseResult {
+        pub case: Case,
+        pub outcome: Outcome,
+    }
+
+    /// Load a JSON array of [`Case`]s.
+    pub fn load_json(json: &str) -> Result<Vec<Case>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Run every case against [`Matchable`], through the slash-delimited path.
+    pub fn run(cases: &[Case]) -> Vec<CaseResult> {
+        cases
+            .iter()
+            .map(|case| CaseResult {
+                case: case.clone(),
+                outcome: run_matchable(case),
+            })
+            .collect()
+    }
+
+    /// Run every case against [`RegexOnly`], treating `pattern` as a whole
+    /// regex; the `flags` suffix is not applied, mirroring how `RegexOnly`
+    /// deserializes.
+    pub fn run_regex_only(cases: &[Case]) -> Vec<CaseResult> {
+        cases
+            .iter()
+            .map(|case| CaseResult {
+                case: case.clone(),
+                outcome: run_regex_only_case(case),
+            })
+            .collect()
+    }
+
+    fn run_matchable(case: &Case) -> Outcome {
+        let literal = case.literal();
+        let matchable = match super::extract_regex(&literal) {
+            Some((body, flags)) => {
+                match super::compile_matchable::<serde::de::value::Error>(body, flags) {
+                    Ok(matchable) => matchable,
+                    Err(_) => return Outcome::CompileError,
+                }
+            }
+            None => Matchable::Str(literal.to_string()),
+        };
+        check(matchable.is_match(&case.input), case.expected_match)
+    }
+
+    fn run_regex_only_case(case: &Case) -> Outcome {
+        match Regex::new(&case.pattern) {
+            Ok(regex) => check(RegexOnly(regex).is_match(&case.input), case.expected_match),
+            Err(_) => Outcome::CompileError,
+        }
+    }
+
+    fn check(actual: bool, expected: bool) -> Outcome {
+        if actual == expected {
+            Outcome::Pass
+        } else {
+            Outcome::Mismatch { expected, actual }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -273,6 +1026,36 @@ mod tests {
         assert!(!matchable.is_match("abc"));
     }
 
+    #[test]
+    fn test_find() {
+        let matchable = Matchable::Str(String::from("abc"));
+        assert_eq!(matchable.find("abc"), Some((0, 3)));
+        assert_eq!(matchable.find("abcd"), None);
+
+        let matchable = Matchable::Regex(Regex::new("b.").unwrap());
+        assert_eq!(matchable.find("abcd"), Some((1, 3)));
+        assert_eq!(matchable.find("xyz"), None);
+    }
+
+    #[test]
+    fn test_captures() {
+        let matchable = Matchable::Str(String::from("abc"));
+        let caps = matchable.captures("abc").unwrap();
+        assert_eq!(caps.get(0).unwrap().as_str(), "abc");
+        assert_eq!(caps.get(0).unwrap().start(), 0);
+        assert_eq!(caps.get(0).unwrap().end(), 3);
+        assert!(caps.get(1).is_none());
+        assert!(caps.name("x").is_none());
+        assert!(matchable.captures("abd").is_none());
+
+        let matchable = Matchable::Regex(Regex::new("(?<year>\\d{4})-(\\d{2})").unwrap());
+        let caps = matchable.captures("2024-01").unwrap();
+        assert_eq!(caps.get(0).unwrap().as_str(), "2024-01");
+        assert_eq!(caps.get(1).unwrap().as_str(), "2024");
+        assert_eq!(caps.get(2).unwrap().as_str(), "01");
+        assert_eq!(caps.name("year").unwrap().as_str(), "2024");
+    }
+
     #[test]
     fn test_str() {
         let matchable = serde_json::from_str(r#""abc""#).unwrap();
@@ -296,6 +1079,139 @@ mod tests {
         assert!(error.to_string().contains("expected a valid regex"));
     }
 
+    #[test]
+    fn test_serialize() {
+        let matchable = Matchable::Str(String::from("abc"));
+        assert_eq!(serde_json::to_string(&matchable).unwrap(), r#""abc""#);
+
+        let matchable = serde_json::from_str::<Matchable>(r#""/\\d+/""#).unwrap();
+        assert_eq!(serde_json::to_string(&matchable).unwrap(), r#""/\\d+/""#);
+
+        let matchable = serde_json::from_str::<Matchable>(r#""/[ab]/im""#).unwrap();
+        assert_eq!(serde_json::to_string(&matchable).unwrap(), r#""/[ab]/im""#);
+
+        // deserialize -> serialize -> deserialize is stable
+        let json = serde_json::to_string(&matchable).unwrap();
+        let round = serde_json::from_str::<Matchable>(&json).unwrap();
+        assert_eq!(matchable, round);
+
+        let regex = serde_json::from_str::<RegexOnly>(r#""\\d+""#).unwrap();
+        assert_eq!(serde_json::to_string(&regex).unwrap(), r#""\\d+""#);
+    }
+
+    #[test]
+    fn test_builder_smart_case() {
+        let lower = MatchableBuilder::new().smart_case(true).build("/cat/").unwrap();
+        assert!(lower.is_match("cat"));
+        assert!(lower.is_match("CAT"));
+
+        let mixed = MatchableBuilder::new().smart_case(true).build("/Cat/").unwrap();
+        assert!(mixed.is_match("Cat"));
+        assert!(!mixed.is_match("cat"));
+
+        // plain strings are promoted to a case-insensitive regex when lowercase
+        let promoted = MatchableBuilder::new().smart_case(true).build("foo").unwrap();
+        assert!(matches!(promoted, Matchable::Regex(_)));
+        assert!(promoted.is_match("FOO"));
+
+        let kept = MatchableBuilder::new().smart_case(true).build("Foo").unwrap();
+        assert!(matches!(kept, Matchable::Str(ref s) if s == "Foo"));
+    }
+
+    #[test]
+    fn test_builder_whole_word_and_anchored() {
+        let whole = MatchableBuilder::new().whole_word(true).build("/cat/").unwrap();
+        assert!(whole.is_match("the cat sat"));
+        assert!(!whole.is_match("category"));
+
+        let anchored = MatchableBuilder::new().anchored(true).build("/cat/").unwrap();
+        assert!(anchored.is_match("cat"));
+        assert!(!anchored.is_match("cats"));
+    }
+
+    #[test]
+    fn test_builder_deserialize_seed() {
+        use serde::de::DeserializeSeed;
+
+        let seed = MatchableBuilder::new().whole_word(true).clone();
+        let mut de = serde_json::Deserializer::from_str(r#""/cat/""#);
+        let matchable = seed.deserialize(&mut de).unwrap();
+        assert!(matchable.is_match("a cat"));
+        assert!(!matchable.is_match("category"));
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_testing_loader() {
+        use crate::testing::{load_json, run, Outcome};
+
+        let json = r#"[
+            {"pattern": "\\d+", "input": "123", "expected_match": true},
+            {"pattern": "[ab]", "input": "A", "expected_match": true, "flags": "i"},
+            {"pattern": "", "input": "anything", "expected_match": true},
+            {"pattern": "a\\/b", "input": "a/b", "expected_match": true},
+            {"pattern": "abc", "input": "xyz", "expected_match": false},
+            {"pattern": "(", "input": "x", "expected_match": false}
+        ]"#;
+
+        let cases = load_json(json).unwrap();
+        let results = run(&cases);
+        assert_eq!(results[0].outcome, Outcome::Pass);
+        assert_eq!(results[1].outcome, Outcome::Pass);
+        assert_eq!(results[2].outcome, Outcome::Pass);
+        assert_eq!(results[3].outcome, Outcome::Pass);
+        assert_eq!(results[4].outcome, Outcome::Pass);
+        assert_eq!(results[5].outcome, Outcome::CompileError);
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn test_matcher() {
+        fn run(matchable: &Matchable, chunks: &[&[u8]]) -> bool {
+            let mut matcher = matchable.matcher().unwrap();
+            for chunk in chunks {
+                matcher.advance(chunk);
+            }
+            matcher.matches()
+        }
+
+        let matchable = Matchable::Str(String::from("abc"));
+        assert!(run(&matchable, &[b"ab", b"c"]));
+        assert!(run(&matchable, &[b"abc"]));
+        assert!(!run(&matchable, &[b"ab"]));
+        assert!(!run(&matchable, &[b"abc", b"d"]));
+
+        let matchable = Matchable::Regex(Regex::new("\\d+").unwrap());
+        assert!(run(&matchable, &[b"x", b"12", b"y"]));
+        assert!(run(&matchable, &[b"xyz1"]));
+        assert!(!run(&matchable, &[b"xy", b"z"]));
+
+        // splitting the input anywhere agrees with `is_match` on the whole
+        let matchable = Matchable::Regex(Regex::new("^a.c$").unwrap());
+        assert_eq!(run(&matchable, &[b"a", b"bc"]), matchable.is_match("abc"));
+        assert_eq!(run(&matchable, &[b"ab", b"cd"]), matchable.is_match("abcd"));
+    }
+
+    #[cfg(feature = "fancy")]
+    #[test]
+    fn test_fancy_fallback() {
+        // A backreference the default engine rejects falls back to fancy_regex.
+        let matchable = serde_json::from_str::<Matchable>(r#""/(foo)\\1/""#).unwrap();
+        assert!(matches!(matchable, Matchable::Fancy(_)));
+        assert!(matchable.is_match("foofoo"));
+        assert!(!matchable.is_match("foobar"));
+        assert!(matchable.try_is_match("foofoo").unwrap());
+
+        // Lookbehind with a flag suffix.
+        let matchable = serde_json::from_str::<Matchable>(r#""/(?<=x)y/i""#).unwrap();
+        assert!(matches!(matchable, Matchable::Fancy(_)));
+        assert!(matchable.is_match("xY"));
+
+        // Ordinary patterns keep using the default engine.
+        let matchable = serde_json::from_str::<Matchable>(r#""/\\d+/""#).unwrap();
+        assert!(matches!(matchable, Matchable::Regex(_)));
+    }
+
     #[test]
     fn test_regex_only() {
         let regex = serde_json::from_str::<RegexOnly>(r#""\\d+""#).unwrap();